@@ -1,14 +1,20 @@
 use core::marker::PhantomData;
 use dominator::clone;
-use dominator::{class, events, html, Dom};
-use futures_signals::signal::{Mutable, SignalExt};
+use dominator::{class, events, html, Dom, DomBuilder};
+use futures_signals::signal::{Mutable, Signal, SignalExt};
 use futures_signals::signal_vec::MutableVec;
 use futures_signals::signal_vec::SignalVecExt;
 use once_cell::sync::Lazy;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use wasm_bindgen::prelude::*;
-use web_sys::{console, DomParser, Node};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{console, Blob, DomParser, Node, Response, Url};
 
 /// A wrapper for a web_sys::Node that can be stored in a static.
 struct NodeSync(Node);
@@ -89,71 +95,295 @@ where
     }
 }
 
+/// A small bag of CSS overrides collected by a hover/active style closure.
+#[derive(Default)]
+pub struct ElementStyle {
+    styles: Vec<(&'static str, String)>,
+}
+
+impl ElementStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn style(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.styles.push((name, value.into()));
+        self
+    }
+
+    pub fn background(self, value: impl Into<String>) -> Self {
+        self.style("background", value)
+    }
+
+    pub fn color(self, value: impl Into<String>) -> Self {
+        self.style("color", value)
+    }
+
+    pub fn border(self, value: impl Into<String>) -> Self {
+        self.style("border", value)
+    }
+}
+
+/// Applies each collected style only while `flag` is set, *removing* the
+/// property when the flag clears.
+///
+/// Because the property is removed rather than reset to some earlier value,
+/// any property named here must not also be set statically on the same element
+/// (the removal would clear that static value, including at rest since the flag
+/// starts `false`). Layer state-only properties, or drive a shared property
+/// entirely through a signal as [`Button`] does for `background`.
+fn apply_state_styles<A>(
+    mut builder: DomBuilder<A>,
+    flag: &Mutable<bool>,
+    styles: Vec<(&'static str, String)>,
+) -> DomBuilder<A>
+where
+    A: AsRef<web_sys::HtmlElement>,
+{
+    for (name, value) in styles {
+        builder = builder.style_signal(
+            name,
+            flag.signal().map(move |on| if on { Some(value.clone()) } else { None }),
+        );
+    }
+    builder
+}
+
+/// Extra styling while the pointer hovers an element, modelled on GPUI's
+/// `hover` style hook.
+pub trait Interactive: Sized {
+    fn hover(self, f: impl FnOnce(ElementStyle) -> ElementStyle) -> Self;
+}
+
+/// Extra styling while the element is pressed, modelled on GPUI's `active`
+/// style hook.
+pub trait Active: Sized {
+    fn active(self, f: impl FnOnce(ElementStyle) -> ElementStyle) -> Self;
+}
+
+impl<A> Interactive for DomBuilder<A>
+where
+    A: AsRef<web_sys::HtmlElement> + AsRef<web_sys::EventTarget> + Clone + 'static,
+{
+    fn hover(self, f: impl FnOnce(ElementStyle) -> ElementStyle) -> Self {
+        let styles = f(ElementStyle::new()).styles;
+        let flag = Mutable::new(false);
+        let builder = self
+            .event(clone!(flag => move |_: events::MouseEnter| flag.set_neq(true)))
+            .event(clone!(flag => move |_: events::MouseLeave| flag.set_neq(false)));
+        apply_state_styles(builder, &flag, styles)
+    }
+}
+
+impl<A> Active for DomBuilder<A>
+where
+    A: AsRef<web_sys::HtmlElement> + AsRef<web_sys::EventTarget> + Clone + 'static,
+{
+    fn active(self, f: impl FnOnce(ElementStyle) -> ElementStyle) -> Self {
+        let styles = f(ElementStyle::new()).styles;
+        let flag = Mutable::new(false);
+        let builder = self
+            .event(clone!(flag => move |_: events::MouseDown| flag.set_neq(true)))
+            .event(clone!(flag => move |_: events::MouseUp| flag.set_neq(false)))
+            .event(clone!(flag => move |_: events::MouseLeave| flag.set_neq(false)));
+        apply_state_styles(builder, &flag, styles)
+    }
+}
+
 #[derive(Clone, Copy)]
 enum ButtonEvent {
     Clicked,
+    Pressed,
+    Released,
+}
+
+/// Where the pointer currently is relative to a [`Button`], driving which
+/// background the pill paints.
+#[derive(Clone, Copy, PartialEq)]
+enum PointerState {
+    Idle,
+    Hover,
+    Press,
+}
+
+/// Appearance overrides for a [`Button`]'s interactive states. Defaults follow
+/// the theme — the pill shows its surface at rest and darkens (in dark mode,
+/// lightens) on press — but
+/// callers can supply their own idle/hover/pressed background.
+pub struct ButtonStyle {
+    pub background: &'static str,
+    pub hover_background: &'static str,
+    pub pressed_background: &'static str,
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        let theme = current_theme();
+        Self {
+            background: theme.surface,
+            hover_background: theme.surface,
+            pressed_background: theme.pressed(),
+        }
+    }
 }
 
 struct Button {}
 
 impl Button {
-    /// Renders a button with given children and an event handler.
-    fn render<B, C, F>(children: C, on_event: F) -> Dom
+    /// Renders a button with the given children, appearance and event handler.
+    fn render<B, C, F>(children: C, style: ButtonStyle, on_event: F) -> Dom
     where
         B: std::borrow::BorrowMut<Dom>,
         C: IntoIterator<Item = B>,
         F: FnMut(ButtonEvent) + 'static,
     {
-        static CLASS: Lazy<String> = Lazy::new(|| {
-            class! {
-                .style("display", "flex")
-                .style("align-items", "center")
-                .style("justify-content", "center")
-                .style("background", "white")
-                .style("border", "1px solid rgba(0, 0, 0, 0.2)")
-                .style("color", "black")
-                .style("padding", "0.5rem")
-                .style("border-radius", "1000rem")
-                .style("cursor", "pointer")
-            }
-            .into()
-        });
-
+        let theme = current_theme();
         let event_dispatcher = Rc::new(EventDispatcher::new(on_event));
+        // Drive `background` entirely through the pointer-state signal so the
+        // idle/hover/pressed colors never fight a static `.style("background")`
+        // (a state style would *remove* the property when it clears).
+        let pointer = Mutable::new(PointerState::Idle);
 
         html!("div", {
             .children(&mut [
                 html!("div", {
-                    .class(&*CLASS)
+                    .style("display", "flex")
+                    .style("align-items", "center")
+                    .style("justify-content", "center")
+                    .style("border", &format!("1px solid {}", theme.border))
+                    .style("color", theme.foreground)
+                    .style("padding", "0.5rem")
+                    .style("border-radius", "1000rem")
+                    .style("cursor", "pointer")
                     .children(children)
-                    .event(move |_: events::Click| {
+                    .style_signal("background", pointer.signal().map(move |state| match state {
+                        PointerState::Idle => style.background,
+                        PointerState::Hover => style.hover_background,
+                        PointerState::Press => style.pressed_background,
+                    }))
+                    .event(clone!(pointer => move |_: events::MouseEnter| pointer.set_neq(PointerState::Hover)))
+                    .event(clone!(pointer => move |_: events::MouseLeave| pointer.set_neq(PointerState::Idle)))
+                    .event(clone!(event_dispatcher => move |_: events::Click| {
                         event_dispatcher.send(ButtonEvent::Clicked);
-                    })
+                    }))
+                    .event(clone!(event_dispatcher, pointer => move |_: events::MouseDown| {
+                        pointer.set_neq(PointerState::Press);
+                        event_dispatcher.send(ButtonEvent::Pressed);
+                    }))
+                    .event(clone!(event_dispatcher, pointer => move |_: events::MouseUp| {
+                        pointer.set_neq(PointerState::Hover);
+                        event_dispatcher.send(ButtonEvent::Released);
+                    }))
                 })
             ])
         })
     }
 }
 
-#[derive(Clone, Copy)]
-enum Icon {
-    Trash,
-    Plus,
-}
-
-/// Renders the SVG markup for an icon.
-fn render_icon_svg(icon: Icon) -> Dom {
-    match icon {
-        Icon::Trash => raw_html!(
-            r#"
-            <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path fill="none" stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16l-1.58 14.22A2 2 0 0 1 16.432 22H7.568a2 2 0 0 1-1.988-1.78zm3.345-2.853A2 2 0 0 1 9.154 2h5.692a2 2 0 0 1 1.81 1.147L18 6H6zM2 6h20m-12 5v5m4-5v5"/></svg>
-            "#
-        ),
-        Icon::Plus => raw_html!(
-            r#"
-            <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"> <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" d="M8 3v10M3 8h10" style="stroke-width: var(--icon-weight, 2);"/></svg>
-            "#
+/// Parses an SVG/HTML fragment string into a `Node`, using the same
+/// "parse once, clone per use" trick as [`raw_html!`] but at runtime so that
+/// arbitrary, caller-supplied glyphs can be cached in the icon registry.
+fn parse_fragment(svg: &str) -> Node {
+    use web_sys::SupportedType;
+
+    let parser = DomParser::new().expect("Failed to create DomParser");
+    let doc = parser
+        .parse_from_string(svg, SupportedType::TextHtml)
+        .expect("Failed to parse SVG string");
+    let body = doc.body().expect("Parsed document has no body");
+    let fragment = doc.create_document_fragment();
+    let children = body.child_nodes();
+    for i in 0..children.length() {
+        let child = children.item(i).expect("Child exists");
+        let clone = child
+            .clone_node_with_deep(true)
+            .expect("Failed to clone node");
+        fragment
+            .append_child(&clone)
+            .expect("Failed to append child");
+    }
+    fragment.into()
+}
+
+/// The built-in "default" glyph pack. Every SVG honours `var(--icon-weight)`
+/// for its stroke width so the fluent `weight`/`font` API keeps working.
+const TRASH_SVG: &str = r#"
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path fill="none" stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" d="M4 6h16l-1.58 14.22A2 2 0 0 1 16.432 22H7.568a2 2 0 0 1-1.988-1.78zm3.345-2.853A2 2 0 0 1 9.154 2h5.692a2 2 0 0 1 1.81 1.147L18 6H6zM2 6h20m-12 5v5m4-5v5" style="stroke-width: var(--icon-weight, 2);"/></svg>
+"#;
+
+const PLUS_SVG: &str = r#"
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"> <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" d="M8 3v10M3 8h10" style="stroke-width: var(--icon-weight, 2);"/></svg>
+"#;
+
+/// Sharp variant of the built-in glyphs (mitred joins), offered as an
+/// alternate flavor the way an editor theme can swap in its own glyph pack.
+const PLUS_SVG_SHARP: &str = r#"
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"> <path stroke="currentColor" stroke-linecap="square" stroke-linejoin="miter" d="M8 3v10M3 8h10" style="stroke-width: var(--icon-weight, 2);"/></svg>
+"#;
+
+const TRASH_SVG_SHARP: &str = r#"
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path fill="none" stroke="currentColor" stroke-linecap="square" stroke-linejoin="miter" d="M4 6h16l-1.58 14.22A2 2 0 0 1 16.432 22H7.568a2 2 0 0 1-1.988-1.78zm3.345-2.853A2 2 0 0 1 9.154 2h5.692a2 2 0 0 1 1.81 1.147L18 6H6zM2 6h20m-12 5v5m4-5v5" style="stroke-width: var(--icon-weight, 2);"/></svg>
+"#;
+
+/// Returns the glyphs belonging to a named flavor.
+fn icons_for_flavor(flavor: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    match flavor {
+        "default" => Some(&[("trash", TRASH_SVG), ("plus", PLUS_SVG)]),
+        "sharp" => Some(&[("trash", TRASH_SVG_SHARP), ("plus", PLUS_SVG_SHARP)]),
+        _ => None,
+    }
+}
+
+/// Global registry mapping icon names to their pre-parsed, cached `Node`s.
+static ICON_REGISTRY: Lazy<Mutex<HashMap<String, NodeSync>>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    for (name, svg) in icons_for_flavor("default").unwrap() {
+        map.insert((*name).to_string(), NodeSync(parse_fragment(svg)));
+    }
+    Mutex::new(map)
+});
+
+/// Registers (or replaces) a named icon, pre-parsing its SVG once so that
+/// later renders only pay for a deep clone of the cached node.
+pub fn register_icon(name: &str, svg: &'static str) {
+    let node = parse_fragment(svg);
+    ICON_REGISTRY
+        .lock()
+        .unwrap()
+        .insert(name.to_string(), NodeSync(node));
+}
+
+/// Loads a named "flavor" of icons at startup, swapping the matching glyphs
+/// in the registry. Unknown flavors are ignored with a console warning.
+pub fn load_icon_flavor(flavor: &str) {
+    match icons_for_flavor(flavor) {
+        Some(set) => {
+            for (name, svg) in set {
+                register_icon(name, svg);
+            }
+        }
+        None => {
+            console::warn_1(&JsValue::from_str(&format!(
+                "Unknown icon flavor '{flavor}', keeping current icons"
+            )));
+        }
+    }
+}
+
+/// Renders the SVG markup for a registered icon, falling back to an empty
+/// placeholder glyph (and a warning) when the name is unknown.
+fn render_icon_svg(name: &str) -> Dom {
+    let registry = ICON_REGISTRY.lock().unwrap();
+    match registry.get(name) {
+        Some(node) => Dom::new(
+            node.0
+                .clone_node_with_deep(true)
+                .expect("Failed to clone cached icon node"),
         ),
+        None => {
+            console::warn_1(&JsValue::from_str(&format!("Unknown icon '{name}'")));
+            html!("span", {})
+        }
     }
 }
 
@@ -198,6 +428,181 @@ impl FontStyle {
         self.style = Some("italic");
         self
     }
+
+    /// Views this preset as a [`TextStyleRefinement`] so it can be folded on
+    /// top of the cascading style stack.
+    fn as_refinement(&self) -> TextStyleRefinement {
+        TextStyleRefinement {
+            size: Some(self.size),
+            leading: Some(self.leading),
+            weight: self.weight,
+            style: self.style,
+            color: None,
+            font_family: None,
+        }
+    }
+}
+
+/// A refinable text style where every field is optional, so it can overlay
+/// only the properties it sets on top of an enclosing style. Inspired by the
+/// refinable style stacks in GPUI.
+#[derive(Clone, Default)]
+pub struct TextStyleRefinement {
+    pub size: Option<f32>,
+    pub leading: Option<f32>,
+    pub weight: Option<&'static str>,
+    pub style: Option<&'static str>,
+    pub color: Option<&'static str>,
+    pub font_family: Option<&'static str>,
+}
+
+/// Alias matching the `refine(&mut self, &Refinement)` spelling.
+pub type Refinement = TextStyleRefinement;
+
+impl TextStyleRefinement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn leading(mut self, leading: f32) -> Self {
+        self.leading = Some(leading);
+        self
+    }
+
+    pub fn weight(mut self, weight: &'static str) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn style(mut self, style: &'static str) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn color(mut self, color: &'static str) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn font_family(mut self, font_family: &'static str) -> Self {
+        self.font_family = Some(font_family);
+        self
+    }
+
+    /// Overlays only the set fields of `other` onto `self`.
+    pub fn refine(&mut self, other: &Refinement) {
+        if other.size.is_some() {
+            self.size = other.size;
+        }
+        if other.leading.is_some() {
+            self.leading = other.leading;
+        }
+        if other.weight.is_some() {
+            self.weight = other.weight;
+        }
+        if other.style.is_some() {
+            self.style = other.style;
+        }
+        if other.color.is_some() {
+            self.color = other.color;
+        }
+        if other.font_family.is_some() {
+            self.font_family = other.font_family;
+        }
+    }
+}
+
+thread_local! {
+    /// The stack of text-style refinements currently in scope. `TextHelper`
+    /// and `IconHelper` fold this before applying their own semantic preset.
+    static TEXT_STYLE_STACK: RefCell<Vec<TextStyleRefinement>> = const { RefCell::new(Vec::new()) };
+    /// The theme in scope, used for `Button`'s surface colors.
+    static CURRENT_THEME: RefCell<Theme> = RefCell::new(Theme::light());
+}
+
+/// Folds every refinement currently on the stack into a single one.
+fn folded_text_style() -> TextStyleRefinement {
+    TEXT_STYLE_STACK.with(|stack| {
+        let mut acc = TextStyleRefinement::default();
+        for refinement in stack.borrow().iter() {
+            acc.refine(refinement);
+        }
+        acc
+    })
+}
+
+/// Pushes `refinement` for the duration of `f`, so everything rendered inside
+/// resolves its effective style against it.
+pub fn with_text_style<R>(refinement: TextStyleRefinement, f: impl FnOnce() -> R) -> R {
+    TEXT_STYLE_STACK.with(|stack| stack.borrow_mut().push(refinement));
+    let result = f();
+    TEXT_STYLE_STACK.with(|stack| {
+        stack.borrow_mut().pop();
+    });
+    result
+}
+
+/// A light or dark theme carrying the default foreground and surface colors.
+#[derive(Clone, Copy)]
+pub struct Theme {
+    pub foreground: &'static str,
+    pub surface: &'static str,
+    pub border: &'static str,
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Self {
+            foreground: "black",
+            surface: "white",
+            border: "rgba(0, 0, 0, 0.2)",
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            foreground: "white",
+            surface: "#1c1c1e",
+            border: "rgba(255, 255, 255, 0.2)",
+        }
+    }
+
+    /// The refinement an app pushes at the root to apply this theme's text color.
+    pub fn refinement(&self) -> TextStyleRefinement {
+        TextStyleRefinement::new().color(self.foreground)
+    }
+
+    /// The surface color a pressed control darkens (or, in dark mode, lightens)
+    /// to.
+    pub fn pressed(&self) -> &'static str {
+        match self.surface {
+            "white" => "#e5e5e5",
+            _ => "#2c2c2e",
+        }
+    }
+}
+
+/// The theme currently in scope.
+fn current_theme() -> Theme {
+    CURRENT_THEME.with(|theme| *theme.borrow())
+}
+
+/// Applies `theme` for the duration of `f`: its foreground color is pushed as
+/// a text-style refinement and its surface colors drive `Button`, so an app
+/// can flip dark mode by wrapping its root in one call.
+pub fn with_theme<R>(theme: Theme, f: impl FnOnce() -> R) -> R {
+    with_text_style(theme.refinement(), || {
+        let previous = current_theme();
+        CURRENT_THEME.with(|current| *current.borrow_mut() = theme);
+        let result = f();
+        CURRENT_THEME.with(|current| *current.borrow_mut() = previous);
+        result
+    })
 }
 
 /// Helper that converts a font weight to an icon stroke width.
@@ -232,16 +637,29 @@ pub struct TextHelper<'a> {
 
 impl<'a> TextHelper<'a> {
     fn render_with_style(self, font_style: FontStyle) -> Dom {
+        // Fold the cascading stack, then overlay this helper's own preset so
+        // the semantic size/weight wins; an enclosing `with_text_style` still
+        // cascades its `color`/`font_family` (which presets never set).
+        let mut style = folded_text_style();
+        style.refine(&font_style.as_refinement());
+        let size = style.size.unwrap_or(font_style.size);
+        let leading = style.leading.unwrap_or(font_style.leading);
         html!("div", {
             .class(&*STANDARD_FONT_CLASS)
-            .style("font-size", scaled_size(font_style.size))
-            .style("line-height", scaled_size(font_style.leading))
+            .style_signal("font-size", scaled_size_signal(size))
+            .style_signal("line-height", scaled_size_signal(leading))
             .text(self.text)
-            .apply_if(font_style.weight.is_some(), |element| {
-                element.style("font-weight", font_style.weight.unwrap())
+            .apply_if(style.weight.is_some(), |element| {
+                element.style("font-weight", style.weight.unwrap())
+            })
+            .apply_if(style.style.is_some(), |element| {
+                element.style("font-style", style.style.unwrap())
             })
-            .apply_if(font_style.style.is_some(), |element| {
-                element.style("font-style", font_style.style.unwrap())
+            .apply_if(style.color.is_some(), |element| {
+                element.style("color", style.color.unwrap())
+            })
+            .apply_if(style.font_family.is_some(), |element| {
+                element.style("font-family", style.font_family.unwrap())
             })
         })
     }
@@ -250,6 +668,12 @@ impl<'a> TextHelper<'a> {
         self.render_with_style(font_style)
     }
 
+    /// Renders with an explicit `FontStyle`; size and leading scale together
+    /// with Dynamic Type so large-text mode stays readable.
+    pub fn font(self, font_style: FontStyle) -> Dom {
+        self.render_with_style(font_style)
+    }
+
     pub fn large_title(self) -> Dom {
         self.render_with_style(FontStyle::new(34.0, 41.0).weight("bold"))
     }
@@ -295,27 +719,569 @@ impl<'a> TextHelper<'a> {
     }
 }
 
-/// Helper to scale text sizes.
-const TEXT_SCALE: f32 = 1.0;
-fn scaled_size(size: f32) -> String {
-    format!("{}px", size * TEXT_SCALE)
+/// Global Dynamic Type scale factor, honouring an accessibility text-size
+/// preference at runtime. Changing it updates every label and icon live.
+static TEXT_SCALE: Lazy<Mutable<f32>> = Lazy::new(|| Mutable::new(1.0));
+
+/// Sets the Dynamic Type scale, clamped to a sane range like the platform
+/// text-size categories.
+pub fn set_text_scale(scale: f32) {
+    TEXT_SCALE.set(scale.clamp(0.8, 2.0));
+}
+
+/// A `px` size that re-emits whenever the Dynamic Type scale changes.
+fn scaled_size_signal(size: f32) -> impl Signal<Item = String> {
+    TEXT_SCALE
+        .signal()
+        .map(move |scale| format!("{}px", size * scale))
 }
 
-/// Creates an icon helper.
-fn icon(icon: Icon) -> IconHelper {
-    IconHelper::new(icon)
+/// Paragraph-level horizontal alignment for [`RichText`].
+#[derive(Clone, Copy)]
+pub enum TextAlign {
+    Leading,
+    Center,
+    Trailing,
+}
+
+impl TextAlign {
+    fn css(self) -> &'static str {
+        match self {
+            TextAlign::Leading => "left",
+            TextAlign::Center => "center",
+            TextAlign::Trailing => "right",
+        }
+    }
+}
+
+/// Line-break behaviour for a [`RichText`] paragraph.
+#[derive(Clone, Copy)]
+pub enum LineBreak {
+    Wrap,
+    NoWrap,
+}
+
+impl LineBreak {
+    fn css(self) -> &'static str {
+        match self {
+            LineBreak::Wrap => "normal",
+            LineBreak::NoWrap => "nowrap",
+        }
+    }
+}
+
+/// A builder for a single flowing paragraph whose runs can each carry their
+/// own [`FontStyle`], so one logical text block can mix weights and styles
+/// (e.g. "Body text **bold word** more text") while sharing the layout.
+pub struct RichText {
+    sections: Vec<(String, FontStyle)>,
+    align: TextAlign,
+    line_break: LineBreak,
+}
+
+impl Default for RichText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+            align: TextAlign::Leading,
+            line_break: LineBreak::Wrap,
+        }
+    }
+
+    /// Appends a styled run.
+    pub fn section(mut self, text: &str, font_style: FontStyle) -> Self {
+        self.sections.push((text.to_string(), font_style));
+        self
+    }
+
+    /// Appends an unstyled `body` run.
+    pub fn plain(self, text: &str) -> Self {
+        self.section(text, FontStyle::new(17.0, 22.0))
+    }
+
+    /// Appends a bold `body` run.
+    pub fn bold(self, text: &str) -> Self {
+        self.section(text, FontStyle::new(17.0, 22.0).weight("bold"))
+    }
+
+    /// Sets the paragraph alignment.
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets whether the paragraph wraps or stays on a single line.
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.line_break = line_break;
+        self
+    }
+
+    /// Emits a wrapping `div` containing one inline `span` per run.
+    pub fn render(self) -> Dom {
+        let align = self.align.css();
+        let white_space = self.line_break.css();
+        html!("div", {
+            .class(&*STANDARD_FONT_CLASS)
+            .style("text-align", align)
+            .style("white-space", white_space)
+            .children(self.sections.into_iter().map(|(text, style)| {
+                html!("span", {
+                    .style_signal("font-size", scaled_size_signal(style.size))
+                    .style_signal("line-height", scaled_size_signal(style.leading))
+                    .text(&text)
+                    .apply_if(style.weight.is_some(), |element| {
+                        element.style("font-weight", style.weight.unwrap())
+                    })
+                    .apply_if(style.style.is_some(), |element| {
+                        element.style("font-style", style.style.unwrap())
+                    })
+                })
+            }).collect::<Vec<_>>())
+        })
+    }
+}
+
+/// Block- and inline-level tags produced by the CommonMark reader, named
+/// after the pulldown-cmark tag shapes they mirror.
+#[derive(Clone)]
+enum MdTag {
+    Heading(u8),
+    Paragraph,
+    Emphasis,
+    Strong,
+    CodeBlock,
+    List { ordered: bool },
+    Item { indent: usize, marker: String },
+    Link { url: String },
+}
+
+/// A flattened CommonMark event, the Start/End/Text stream the renderer walks.
+enum MdEvent {
+    Start(MdTag),
+    End(MdTag),
+    Text(String),
+    InlineCode(String),
+}
+
+/// Events emitted by a rendered [`markdown`] block so callers can intercept
+/// navigation rather than letting a link follow through.
+#[derive(Clone)]
+pub enum MarkdownEvent {
+    LinkClicked(String),
+}
+
+/// Returns the heading level (1-6) if `line` opens an ATX heading.
+fn heading_level(line: &str) -> Option<u8> {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+        Some(hashes as u8)
+    } else {
+        None
+    }
+}
+
+/// Parses a single list item line into `(nesting depth, marker, content)`.
+fn parse_list_item(line: &str) -> Option<(usize, String, String)> {
+    let indent = line.len() - line.trim_start().len();
+    let depth = indent / 2;
+    let trimmed = line.trim_start();
+    if let Some(rest) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+        return Some((depth, "•".to_string(), rest.to_string()));
+    }
+    let digits: String = trimmed.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if !digits.is_empty() {
+        if let Some(rest) = trimmed[digits.len()..].strip_prefix(". ") {
+            return Some((depth, format!("{digits}."), rest.to_string()));
+        }
+    }
+    None
+}
+
+/// Scans a line of inline markdown, emitting Start/End/Text events for the
+/// supported spans: `**strong**`, `*em*`/`_em_`, `` `code` `` and `[t](url)`.
+fn parse_inline(line: &str, out: &mut Vec<MdEvent>) {
+    let mut buf = String::new();
+    let mut i = 0;
+    while i < line.len() {
+        let rest = &line[i..];
+        if let Some(r) = rest.strip_prefix("**") {
+            if let Some(end) = r.find("**") {
+                flush_text(&mut buf, out);
+                out.push(MdEvent::Start(MdTag::Strong));
+                parse_inline(&r[..end], out);
+                out.push(MdEvent::End(MdTag::Strong));
+                i += 2 + end + 2;
+                continue;
+            }
+        }
+        if let Some(marker) = ["*", "_"].iter().find(|m| rest.starts_with(**m)) {
+            let r = &rest[1..];
+            if let Some(end) = r.find(*marker) {
+                flush_text(&mut buf, out);
+                out.push(MdEvent::Start(MdTag::Emphasis));
+                parse_inline(&r[..end], out);
+                out.push(MdEvent::End(MdTag::Emphasis));
+                i += 1 + end + 1;
+                continue;
+            }
+        }
+        if let Some(r) = rest.strip_prefix('`') {
+            if let Some(end) = r.find('`') {
+                flush_text(&mut buf, out);
+                out.push(MdEvent::InlineCode(r[..end].to_string()));
+                i += 1 + end + 1;
+                continue;
+            }
+        }
+        if rest.starts_with('[') {
+            if let Some(bracket) = rest.find("](") {
+                if let Some(paren) = rest[bracket + 2..].find(')') {
+                    let text = &rest[1..bracket];
+                    let url = &rest[bracket + 2..bracket + 2 + paren];
+                    flush_text(&mut buf, out);
+                    out.push(MdEvent::Start(MdTag::Link { url: url.to_string() }));
+                    parse_inline(text, out);
+                    out.push(MdEvent::End(MdTag::Link { url: url.to_string() }));
+                    i += bracket + 2 + paren + 1;
+                    continue;
+                }
+            }
+        }
+        let ch = rest.chars().next().unwrap();
+        buf.push(ch);
+        i += ch.len_utf8();
+    }
+    flush_text(&mut buf, out);
+}
+
+fn flush_text(buf: &mut String, out: &mut Vec<MdEvent>) {
+    if !buf.is_empty() {
+        out.push(MdEvent::Text(std::mem::take(buf)));
+    }
+}
+
+/// Walks the source line by line, producing a flat event stream for the
+/// CommonMark subset this crate understands (headings, paragraphs, fenced
+/// code, emphasis/strong, inline code, links and flat lists).
+fn parse_markdown(src: &str) -> Vec<MdEvent> {
+    fn flush_para(para: &mut Vec<String>, out: &mut Vec<MdEvent>) {
+        if para.is_empty() {
+            return;
+        }
+        let joined = para.join(" ");
+        para.clear();
+        out.push(MdEvent::Start(MdTag::Paragraph));
+        parse_inline(&joined, out);
+        out.push(MdEvent::End(MdTag::Paragraph));
+    }
+    fn close_list(in_list: &mut bool, out: &mut Vec<MdEvent>) {
+        if *in_list {
+            out.push(MdEvent::End(MdTag::List { ordered: false }));
+            *in_list = false;
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut para: Vec<String> = Vec::new();
+    let mut in_list = false;
+    let lines: Vec<&str> = src.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            flush_para(&mut para, &mut out);
+            close_list(&mut in_list, &mut out);
+            let mut code: Vec<&str> = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                code.push(lines[i]);
+                i += 1;
+            }
+            i += 1;
+            out.push(MdEvent::Start(MdTag::CodeBlock));
+            out.push(MdEvent::Text(code.join("\n")));
+            out.push(MdEvent::End(MdTag::CodeBlock));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush_para(&mut para, &mut out);
+            close_list(&mut in_list, &mut out);
+            i += 1;
+            continue;
+        }
+
+        if let Some(level) = heading_level(trimmed) {
+            flush_para(&mut para, &mut out);
+            close_list(&mut in_list, &mut out);
+            let content = trimmed[level as usize..].trim_start();
+            out.push(MdEvent::Start(MdTag::Heading(level)));
+            parse_inline(content, &mut out);
+            out.push(MdEvent::End(MdTag::Heading(level)));
+            i += 1;
+            continue;
+        }
+
+        if let Some((indent, marker, content)) = parse_list_item(line) {
+            flush_para(&mut para, &mut out);
+            if !in_list {
+                out.push(MdEvent::Start(MdTag::List { ordered: false }));
+                in_list = true;
+            }
+            out.push(MdEvent::Start(MdTag::Item { indent, marker }));
+            parse_inline(&content, &mut out);
+            out.push(MdEvent::End(MdTag::Item {
+                indent,
+                marker: String::new(),
+            }));
+            i += 1;
+            continue;
+        }
+
+        close_list(&mut in_list, &mut out);
+        para.push(line.trim().to_string());
+        i += 1;
+    }
+
+    flush_para(&mut para, &mut out);
+    close_list(&mut in_list, &mut out);
+    out
+}
+
+/// The resolved style of an inline run while walking the event stream.
+#[derive(Clone)]
+struct RunStyle {
+    font: FontStyle,
+    mono: bool,
+    link: Option<String>,
+}
+
+impl RunStyle {
+    fn body() -> Self {
+        Self {
+            font: FontStyle::new(17.0, 22.0),
+            mono: false,
+            link: None,
+        }
+    }
+
+    fn mono() -> Self {
+        Self {
+            font: FontStyle::new(15.0, 20.0),
+            mono: true,
+            link: None,
+        }
+    }
+}
+
+/// Maps a heading level onto the Apple-style type ramp.
+fn heading_style(level: u8) -> RunStyle {
+    let font = match level {
+        1 => FontStyle::new(34.0, 41.0).weight("bold"),
+        2 => FontStyle::new(28.0, 34.0).weight("bold"),
+        _ => FontStyle::new(22.0, 28.0).weight("bold"),
+    };
+    RunStyle {
+        font,
+        mono: false,
+        link: None,
+    }
+}
+
+/// Context for the block currently being assembled from inline runs.
+enum BlockCx {
+    Heading,
+    Paragraph,
+    Item { indent: usize, marker: String },
+    CodeBlock,
+}
+
+/// Renders a single inline run as a `span`, wiring a [`MarkdownEvent`] click
+/// dispatch for link runs.
+fn markdown_run<F>(
+    text: &str,
+    style: &RunStyle,
+    dispatcher: &Rc<EventDispatcher<MarkdownEvent, F>>,
+) -> Dom
+where
+    F: FnMut(MarkdownEvent) + 'static,
+{
+    html!("span", {
+        .style_signal("font-size", scaled_size_signal(style.font.size))
+        .style_signal("line-height", scaled_size_signal(style.font.leading))
+        .text(text)
+        .apply_if(style.font.weight.is_some(), |element| {
+            element.style("font-weight", style.font.weight.unwrap())
+        })
+        .apply_if(style.font.style.is_some(), |element| {
+            element.style("font-style", style.font.style.unwrap())
+        })
+        .apply_if(style.mono, |element| {
+            element.style("font-family", "ui-monospace, SFMono-Regular, Menlo, monospace")
+        })
+        .apply_if(style.link.is_some(), |element| {
+            let url = style.link.clone().unwrap();
+            let dispatcher = dispatcher.clone();
+            element
+                .style("color", "#0a84ff")
+                .style("text-decoration", "underline")
+                .style("cursor", "pointer")
+                .event(move |_: events::Click| {
+                    dispatcher.send(MarkdownEvent::LinkClicked(url.clone()));
+                })
+        })
+    })
+}
+
+/// Wraps the collected inline runs for a closed block into a `Dom` child.
+fn markdown_block(cx: BlockCx, runs: Vec<Dom>) -> Dom {
+    match cx {
+        BlockCx::CodeBlock => html!("div", {
+            .style("white-space", "pre-wrap")
+            .style("background", "rgba(0, 0, 0, 0.05)")
+            .style("padding", "0.5rem")
+            .style("border-radius", "0.375rem")
+            .style("margin", "0.5rem 0")
+            .children(runs)
+        }),
+        BlockCx::Item { indent, marker } => html!("div", {
+            .style("display", "flex")
+            .style("padding-left", &format!("{}rem", 1.0 + indent as f32))
+            .children(&mut [
+                html!("span", {
+                    .style("margin-right", "0.5rem")
+                    .text(&marker)
+                }),
+                html!("div", {
+                    .children(runs)
+                }),
+            ])
+        }),
+        BlockCx::Heading | BlockCx::Paragraph => html!("div", {
+            .style("margin", "0.25rem 0")
+            .children(runs)
+        }),
+    }
+}
+
+/// Parses CommonMark and renders it with this crate's typography scale, using
+/// a default handler that logs link clicks. Use [`markdown_with`] to intercept
+/// navigation.
+pub fn markdown(src: &str) -> Dom {
+    markdown_with(src, |event| match event {
+        MarkdownEvent::LinkClicked(url) => {
+            console::log_1(&JsValue::from_str(&format!("LinkClicked: {url}")));
+        }
+    })
+}
+
+/// Like [`markdown`], but dispatches [`MarkdownEvent`]s to `on_event` so the
+/// caller can intercept link navigation.
+pub fn markdown_with<F>(src: &str, on_event: F) -> Dom
+where
+    F: FnMut(MarkdownEvent) + 'static,
+{
+    let dispatcher = Rc::new(EventDispatcher::new(on_event));
+    let events = parse_markdown(src);
+
+    let mut blocks: Vec<Dom> = Vec::new();
+    let mut style_stack: Vec<RunStyle> = Vec::new();
+    let mut runs: Vec<Dom> = Vec::new();
+    let mut block_cx: Option<BlockCx> = None;
+
+    for event in events {
+        match event {
+            MdEvent::Start(tag) => match tag {
+                MdTag::Heading(level) => {
+                    block_cx = Some(BlockCx::Heading);
+                    style_stack.push(heading_style(level));
+                }
+                MdTag::Paragraph => {
+                    block_cx = Some(BlockCx::Paragraph);
+                    style_stack.push(RunStyle::body());
+                }
+                MdTag::Item { indent, marker } => {
+                    block_cx = Some(BlockCx::Item { indent, marker });
+                    style_stack.push(RunStyle::body());
+                }
+                MdTag::CodeBlock => {
+                    block_cx = Some(BlockCx::CodeBlock);
+                    style_stack.push(RunStyle::mono());
+                }
+                MdTag::List { .. } => {}
+                MdTag::Emphasis => {
+                    let mut style = style_stack.last().cloned().unwrap_or_else(RunStyle::body);
+                    style.font = style.font.italic();
+                    style_stack.push(style);
+                }
+                MdTag::Strong => {
+                    let mut style = style_stack.last().cloned().unwrap_or_else(RunStyle::body);
+                    style.font = style.font.weight("bold");
+                    style_stack.push(style);
+                }
+                MdTag::Link { url } => {
+                    let mut style = style_stack.last().cloned().unwrap_or_else(RunStyle::body);
+                    style.link = Some(url);
+                    style_stack.push(style);
+                }
+            },
+            MdEvent::End(tag) => match tag {
+                MdTag::Heading(_)
+                | MdTag::Paragraph
+                | MdTag::Item { .. }
+                | MdTag::CodeBlock => {
+                    style_stack.pop();
+                    if let Some(cx) = block_cx.take() {
+                        blocks.push(markdown_block(cx, std::mem::take(&mut runs)));
+                    }
+                }
+                MdTag::List { .. } => {}
+                MdTag::Emphasis | MdTag::Strong | MdTag::Link { .. } => {
+                    style_stack.pop();
+                }
+            },
+            MdEvent::Text(text) => {
+                let style = style_stack.last().cloned().unwrap_or_else(RunStyle::body);
+                runs.push(markdown_run(&text, &style, &dispatcher));
+            }
+            MdEvent::InlineCode(text) => {
+                let mut style = style_stack.last().cloned().unwrap_or_else(RunStyle::body);
+                style.mono = true;
+                runs.push(markdown_run(&text, &style, &dispatcher));
+            }
+        }
+    }
+
+    html!("div", {
+        .class(&*STANDARD_FONT_CLASS)
+        .children(blocks)
+    })
+}
+
+/// Creates an icon helper for a registered icon name.
+pub fn icon_named(name: &str) -> IconHelper {
+    IconHelper::new(name)
 }
 
 /// Helper for rendering icons with fluent styling.
-struct IconHelper {
-    icon: Icon,
+pub struct IconHelper {
+    name: String,
     style: IconStyle,
 }
 
 impl IconHelper {
-    pub fn new(icon: Icon) -> Self {
+    pub fn new(name: &str) -> Self {
         Self {
-            icon,
+            name: name.to_string(),
             style: IconStyle::new(16.0),
         }
     }
@@ -393,35 +1359,223 @@ impl IconHelper {
 
     /// Finalizes the icon rendering.
     fn finish(self) -> Dom {
+        // Fold the cascading stack, then overlay the icon's own preset, like
+        // `TextHelper`: the icon's size always wins, its stroke weight wins where
+        // set (otherwise a `with_text_style` font weight is mapped to one), and a
+        // theme (e.g. dark mode) can tint `currentColor` without per-call edits.
+        let style = folded_text_style();
+        let size = self.style.size;
+        let weight = self
+            .style
+            .weight
+            .or_else(|| font_weight_to_icon_weight(style.weight));
         html!("div", {
             .class(&*STANDARD_FONT_CLASS)
             .style("display", "inline-block")
-            .style("width", &scaled_size(self.style.size))
-            .style("height", &scaled_size(self.style.size))
-            .apply_if(self.style.weight.is_some(), |element| {
-                element.style("--icon-weight", &format!("{}px", self.style.weight.unwrap()))
+            .style_signal("width", scaled_size_signal(size))
+            .style_signal("height", scaled_size_signal(size))
+            .apply_if(style.color.is_some(), |element| {
+                element.style("color", style.color.unwrap())
+            })
+            .apply_if(weight.is_some(), |element| {
+                element.style("--icon-weight", &format!("{}px", weight.unwrap()))
             })
-            .child(render_icon_svg(self.icon))
+            .child(render_icon_svg(&self.name))
         })
     }
 }
 
+/// The lifecycle of an asynchronously loaded resource.
+pub enum LoadState {
+    Loading,
+    Loaded(Node),
+    Failed,
+}
+
+/// Returned by a [`ResourceProvider`] when a resource cannot be produced.
+#[derive(Debug)]
+pub struct ResourceError;
+
+/// A pluggable network layer for remote resources, like the shared network
+/// callback injected into headless browser engines. Supply a custom provider
+/// to cache responses or to serve bundled assets instead of hitting the wire.
+pub trait ResourceProvider {
+    /// Resolves a remote SVG `url` to its markup.
+    fn fetch_text(&self, url: String) -> Pin<Box<dyn Future<Output = Result<String, ResourceError>>>>;
+
+    /// Resolves a remote binary `url` (e.g. image data) to its raw bytes.
+    ///
+    /// The default fetches over the wire like [`FetchProvider`]; override it to
+    /// serve bundled image assets or a cache alongside [`fetch_text`].
+    fn fetch_bytes(&self, url: String) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResourceError>>>> {
+        Box::pin(fetch_bytes_over_wire(url))
+    }
+}
+
+/// The default provider, backed by the browser Fetch API.
+pub struct FetchProvider;
+
+impl ResourceProvider for FetchProvider {
+    fn fetch_text(&self, url: String) -> Pin<Box<dyn Future<Output = Result<String, ResourceError>>>> {
+        Box::pin(async move {
+            let window = web_sys::window().ok_or(ResourceError)?;
+            let response = JsFuture::from(window.fetch_with_str(&url))
+                .await
+                .map_err(|_| ResourceError)?;
+            let response: Response = response.dyn_into().map_err(|_| ResourceError)?;
+            if !response.ok() {
+                return Err(ResourceError);
+            }
+            let text = JsFuture::from(response.text().map_err(|_| ResourceError)?)
+                .await
+                .map_err(|_| ResourceError)?;
+            text.as_string().ok_or(ResourceError)
+        })
+    }
+}
+
+/// Fetches `url` and returns its response body as raw bytes, the binary
+/// counterpart to [`FetchProvider::fetch_text`].
+async fn fetch_bytes_over_wire(url: String) -> Result<Vec<u8>, ResourceError> {
+    let window = web_sys::window().ok_or(ResourceError)?;
+    let response = JsFuture::from(window.fetch_with_str(&url))
+        .await
+        .map_err(|_| ResourceError)?;
+    let response: Response = response.dyn_into().map_err(|_| ResourceError)?;
+    if !response.ok() {
+        return Err(ResourceError);
+    }
+    let buffer = JsFuture::from(response.array_buffer().map_err(|_| ResourceError)?)
+        .await
+        .map_err(|_| ResourceError)?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Wraps decoded image `bytes` in an `<img>` node backed by an object URL, so
+/// provider-supplied (cached or bundled) image data can be shown inline.
+fn bytes_to_image_node(bytes: &[u8]) -> Result<Node, ResourceError> {
+    let array = js_sys::Uint8Array::from(bytes);
+    let parts = js_sys::Array::of1(&array);
+    let blob = Blob::new_with_u8_array_sequence(&parts).map_err(|_| ResourceError)?;
+    let object_url = Url::create_object_url_with_blob(&blob).map_err(|_| ResourceError)?;
+    let document = web_sys::window()
+        .ok_or(ResourceError)?
+        .document()
+        .ok_or(ResourceError)?;
+    let img = document.create_element("img").map_err(|_| ResourceError)?;
+    img.set_attribute("src", &object_url).map_err(|_| ResourceError)?;
+    img.set_attribute("style", "max-width: 100%").map_err(|_| ResourceError)?;
+    Ok(img.into())
+}
+
+thread_local! {
+    /// The provider used by [`image`] and [`remote_icon`].
+    static RESOURCE_PROVIDER: RefCell<Rc<dyn ResourceProvider>> =
+        RefCell::new(Rc::new(FetchProvider));
+}
+
+/// Installs a custom [`ResourceProvider`] (e.g. a caching or asset-bundle one).
+pub fn set_resource_provider(provider: Rc<dyn ResourceProvider>) {
+    RESOURCE_PROVIDER.with(|p| *p.borrow_mut() = provider);
+}
+
+fn resource_provider() -> Rc<dyn ResourceProvider> {
+    RESOURCE_PROVIDER.with(|p| p.borrow().clone())
+}
+
+/// A neutral placeholder shown while a remote resource is still loading.
+fn loading_placeholder() -> Dom {
+    html!("span", {
+        .style("display", "inline-block")
+        .style("width", "1em")
+        .style("height", "1em")
+        .style("background", "rgba(0, 0, 0, 0.1)")
+        .style("border-radius", "0.25em")
+    })
+}
+
+/// The fallback glyph shown when a remote resource fails to load.
+fn fallback_glyph() -> Dom {
+    html!("span", {
+        .style("display", "inline-block")
+        .style("width", "1em")
+        .style("height", "1em")
+        .style("border", "1px dashed rgba(0, 0, 0, 0.3)")
+        .style("border-radius", "0.25em")
+    })
+}
+
+/// Loads an external SVG icon from `url`, returning a `Dom` immediately that
+/// shows a placeholder while the fetch runs and swaps in the parsed glyph (or
+/// a fallback on error) through a signal.
+pub fn remote_icon(url: &str) -> Dom {
+    let state = Mutable::new(LoadState::Loading);
+    let provider = resource_provider();
+    let url = url.to_string();
+    spawn_local(clone!(state => async move {
+        match provider.fetch_text(url).await {
+            Ok(svg) => state.set(LoadState::Loaded(parse_fragment(&svg))),
+            Err(_) => state.set(LoadState::Failed),
+        }
+    }));
+    html!("div", {
+        .style("display", "inline-block")
+        .child_signal(state.signal_ref(|state| Some(match state {
+            LoadState::Loading => loading_placeholder(),
+            LoadState::Loaded(node) => Dom::new(
+                node.clone_node_with_deep(true)
+                    .expect("Failed to clone loaded icon node"),
+            ),
+            LoadState::Failed => fallback_glyph(),
+        })))
+    })
+}
+
+/// Loads a remote image from `url` through the active [`ResourceProvider`],
+/// returning a `Dom` immediately that shows a placeholder while the fetch runs
+/// and swaps in the decoded image (or a fallback on error) through a signal.
+pub fn image(url: &str) -> Dom {
+    let state = Mutable::new(LoadState::Loading);
+    let provider = resource_provider();
+    let url = url.to_string();
+    spawn_local(clone!(state => async move {
+        match provider.fetch_bytes(url).await {
+            Ok(bytes) => match bytes_to_image_node(&bytes) {
+                Ok(node) => state.set(LoadState::Loaded(node)),
+                Err(_) => state.set(LoadState::Failed),
+            },
+            Err(_) => state.set(LoadState::Failed),
+        }
+    }));
+    html!("div", {
+        .style("display", "inline-block")
+        .child_signal(state.signal_ref(|state| Some(match state {
+            LoadState::Loading => loading_placeholder(),
+            LoadState::Loaded(node) => Dom::new(
+                node.clone_node_with_deep(true)
+                    .expect("Failed to clone loaded image node"),
+            ),
+            LoadState::Failed => fallback_glyph(),
+        })))
+    })
+}
+
 /// Example function that renders various icon sizes.
 fn icon_test() -> Dom {
     html!("div", {
         .children(&mut [
-            icon(Icon::Plus).large_title(),
-            icon(Icon::Plus).title(),
-            icon(Icon::Plus).title2(),
-            icon(Icon::Plus).title3(),
-            icon(Icon::Plus).headline(),
-            icon(Icon::Plus).body(),
-            icon(Icon::Plus).callout(),
-            icon(Icon::Plus).subheadline(),
-            icon(Icon::Plus).footnote(),
-            icon(Icon::Plus).caption(),
-            icon(Icon::Plus).caption2(),
-            icon(Icon::Plus).custom(FontStyle::new(18.0, 24.0).weight("500").italic()),
+            icon_named("plus").large_title(),
+            icon_named("plus").title(),
+            icon_named("plus").title2(),
+            icon_named("plus").title3(),
+            icon_named("plus").headline(),
+            icon_named("plus").body(),
+            icon_named("plus").callout(),
+            icon_named("plus").subheadline(),
+            icon_named("plus").footnote(),
+            icon_named("plus").caption(),
+            icon_named("plus").caption2(),
+            icon_named("plus").custom(FontStyle::new(18.0, 24.0).weight("500").italic()),
         ])
     })
 }